@@ -6,9 +6,15 @@
 //! # Features
 //!
 //! - Convert between binary (base-2), octal (base-8), decimal (base-10), and hexadecimal (base-16)
-//! - Support for common number prefixes (0b, 0o, 0x)
+//! - Convert between arbitrary radixes from base 2 to base 36 via `NumSystem::Radix`
+//! - Convert to/from Base32 and Base64 (RFC 4648) via `NumSystem::Base32`/`NumSystem::Base64`
+//! - Support for common number prefixes (0b, 0o, 0x, 0t, 0s)
+//! - Tolerant of `_`/space digit separators and trailing Rust integer type suffixes on input
+//! - Fractional (radix-point) conversion, e.g. `3.14` (dec) to hex
 //! - Configurable output width with zero padding
 //! - Optional digit grouping for improved readability
+//! - Signed decimal input (a leading `-`) and, with `Config::twos_complement`, fixed-width
+//!   two's-complement output for inspecting register values
 //!
 //! # Examples
 //!
@@ -16,11 +22,13 @@
 //! use nconv::{Config, NumSystem};
 //!
 //! let config = Config {
-//!     number: String::from("255"),
+//!     number: vec![String::from("255")],
 //!     src_base: NumSystem::Dec,
 //!     tgt_base: NumSystem::Hex,
 //!     width: 4,
 //!     grouping: 0,
+//!     twos_complement: false,
+//!     bits: 8,
 //! };
 //!
 //! nconv::run(&config).unwrap();  // Prints: 00FF
@@ -33,20 +41,88 @@
 //! - Number overflow
 //! - Invalid base combinations
 //! - Mismatched prefixes
-use clap::ValueEnum;
+//! - Two's-complement bit widths or magnitudes that don't fit
 use std::fmt::Display;
+use std::io::{Read, Write};
+use std::str::FromStr;
 
 /// Represents the supported number systems for conversion.
-#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum NumSystem {
     /// Binary number system (base 2).
-    Bin = 2,
+    Bin,
     /// Octal number system (base 8).
-    Oct = 8,
+    Oct,
     /// Decimal number system (base 10).
-    Dec = 10,
+    Dec,
     /// Hexadecimal number system (base 16).
-    Hex = 16,
+    Hex,
+    /// An arbitrary radix in the range 2-36, using the digit alphabet `0-9A-Z`.
+    Radix(u32),
+    /// Base32 (RFC 4648), using the `0t` prefix on input.
+    Base32,
+    /// Base64 (RFC 4648), using the `0s` prefix on input.
+    Base64,
+    /// Raw big-endian bytes read from/written to stdin/stdout, rather than an ASCII string.
+    Raw,
+}
+
+impl NumSystem {
+    /// Returns the numeric radix (base) that this number system represents.
+    ///
+    /// `Base32`, `Base64` and `Raw` are not positional radixes; they are encoded/decoded as
+    /// bytes instead, so this method is only meaningful for the other variants and panics
+    /// for these three.
+    pub fn radix(&self) -> u32 {
+        match self {
+            NumSystem::Bin => 2,
+            NumSystem::Oct => 8,
+            NumSystem::Dec => 10,
+            NumSystem::Hex => 16,
+            NumSystem::Radix(r) => *r,
+            NumSystem::Base32 | NumSystem::Base64 | NumSystem::Raw => {
+                unreachable!("Base32/Base64/Raw are encoded as byte strings, not positional digits")
+            }
+        }
+    }
+
+    /// Returns `true` if this number system is encoded/decoded as a byte string rather than
+    /// positional digits.
+    fn is_byte_encoded(&self) -> bool {
+        matches!(self, NumSystem::Base32 | NumSystem::Base64)
+    }
+}
+
+impl FromStr for NumSystem {
+    type Err = String;
+
+    /// Parses a number system from either a well-known name (`bin`, `oct`, `dec`, `hex`,
+    /// `base32`/`b32`, `base64`/`b64`, `raw`, case-insensitive) or a plain integer radix in
+    /// the range 2-36.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "bin" => Ok(NumSystem::Bin),
+            "oct" => Ok(NumSystem::Oct),
+            "dec" => Ok(NumSystem::Dec),
+            "hex" => Ok(NumSystem::Hex),
+            "base32" | "b32" => Ok(NumSystem::Base32),
+            "base64" | "b64" => Ok(NumSystem::Base64),
+            "raw" => Ok(NumSystem::Raw),
+            other => {
+                let radix: u32 = other.parse().map_err(|_| {
+                    format!(
+                        "'{}' is not a valid number system (expected bin, oct, dec, hex, base32, base64, raw, or a radix from 2 to 36)",
+                        s
+                    )
+                })?;
+                if (2..=36).contains(&radix) {
+                    Ok(NumSystem::Radix(radix))
+                } else {
+                    Err(format!("radix {} is out of range (must be 2-36)", radix))
+                }
+            }
+        }
+    }
 }
 
 /// Configuration for number conversion.
@@ -55,21 +131,31 @@ pub struct Config {
     pub src_base: NumSystem,
     /// The target number system to convert to.
     pub tgt_base: NumSystem,
-    /// The input number as a string.
-    pub number: String,
+    /// The input numbers to convert, one per string. When empty (and `src_base` is not
+    /// `NumSystem::Raw`), whitespace-separated tokens are instead read from stdin, one
+    /// result printed per token.
+    pub number: Vec<String>,
     /// The size of digit grouping (0 for no grouping).
     pub grouping: u32,
     /// The minimum width for zero-padding the output.
     pub width: u32,
+    /// Emit negative values as their two's-complement bit pattern in `bits` bits instead of a
+    /// `-`-prefixed magnitude.
+    pub twos_complement: bool,
+    /// The bit width used to compute the two's-complement modulus when `twos_complement` is set.
+    pub bits: u32,
 }
 
 impl Config {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         src_base: NumSystem,
         tgt_base: NumSystem,
-        number: String,
+        number: Vec<String>,
         grouping: u32,
         width: u32,
+        twos_complement: bool,
+        bits: u32,
     ) -> Config {
         Config {
             src_base,
@@ -77,6 +163,8 @@ impl Config {
             number,
             grouping,
             width,
+            twos_complement,
+            bits,
         }
     }
 }
@@ -90,6 +178,17 @@ pub enum ConversionError {
     NumberOverflow,
     /// Invalid base specified for conversion.
     InvalidBase,
+    /// A `NumSystem::Radix` value fell outside the supported 2-36 range.
+    UnsupportedRadix(u32),
+    /// The input contained more than one `.` radix point.
+    MultipleRadixPoints,
+    /// A requested two's-complement bit width could not be used to compute a `2^bits` modulus
+    /// (the modulus itself must fit in a `u128`).
+    UnsupportedBitWidth(u32),
+    /// A signed value's magnitude does not fit in the requested two's-complement bit width.
+    MagnitudeExceedsBitWidth(u32),
+    /// Reading from stdin or writing to stdout failed.
+    Io(std::io::Error),
 }
 
 impl Display for ConversionError {
@@ -98,14 +197,34 @@ impl Display for ConversionError {
             ConversionError::InvalidDigit(c) => write!(f, "invalid digit: '{}'", c),
             ConversionError::NumberOverflow => write!(f, "input value exceeds 128 bit limit"),
             ConversionError::InvalidBase => write!(f, "invalid base"),
+            ConversionError::UnsupportedRadix(r) => {
+                write!(f, "unsupported radix {} (must be 2-36)", r)
+            }
+            ConversionError::MultipleRadixPoints => {
+                write!(f, "input contains more than one radix point ('.')")
+            }
+            ConversionError::UnsupportedBitWidth(bits) => {
+                write!(f, "unsupported two's-complement bit width: {}", bits)
+            }
+            ConversionError::MagnitudeExceedsBitWidth(bits) => {
+                write!(
+                    f,
+                    "value does not fit in {}-bit two's-complement form",
+                    bits
+                )
+            }
+            ConversionError::Io(e) => write!(f, "i/o error: {}", e),
         }
     }
 }
 
 /// Converts a number string from one numeric base to another.
 ///
-/// This function supports conversion between binary, octal, decimal, and hexadecimal number systems.
-/// It recognizes common prefixes (0b, 0o, 0x) when they match the source base.
+/// This function supports conversion between binary, octal, decimal, and hexadecimal number
+/// systems, any arbitrary radix from 2 to 36 via `NumSystem::Radix`, and Base32/Base64 via
+/// `NumSystem::Base32`/`NumSystem::Base64`. It recognizes common prefixes (0b, 0o, 0x, 0t, 0s)
+/// when they match the source base, an optional fractional part after a `.` radix point
+/// (e.g. `3.14`, `0b101.101`), and an optional leading `-` for the digit-based number systems.
 ///
 /// # Arguments
 ///
@@ -121,6 +240,8 @@ impl Display for ConversionError {
 ///   - Number overflow
 ///   - Invalid base combination
 ///   - Mismatched prefix and source base
+///   - A `NumSystem::Radix` outside the 2-36 range
+///   - More than one `.` radix point
 ///
 /// # Examples
 ///
@@ -132,13 +253,205 @@ impl Display for ConversionError {
 ///
 /// let hex_result = convert_base("0xFF", NumSystem::Hex, NumSystem::Dec);
 /// assert_eq!(hex_result.unwrap(), "255");
+///
+/// // base-2 to base-36
+/// let radix_result = convert_base("0011", NumSystem::Radix(2), NumSystem::Radix(36));
+/// assert_eq!(radix_result.unwrap(), "3");
+///
+/// let base64_result = convert_base("0xAABB", NumSystem::Hex, NumSystem::Base64);
+/// assert_eq!(base64_result.unwrap(), "qrs=");
+///
+/// let fractional_result = convert_base("3.14", NumSystem::Dec, NumSystem::Hex);
+/// assert_eq!(fractional_result.unwrap(), "3.23D70A3D70A3D70A3D70A3D70A3D70A3");
+///
+/// let signed_result = convert_base("-1", NumSystem::Dec, NumSystem::Hex);
+/// assert_eq!(signed_result.unwrap(), "-1");
 /// ```
 pub fn convert_base(
     num: &str,
     src: NumSystem,
     target: NumSystem,
 ) -> Result<String, ConversionError> {
-    let digits = "0123456789ABCDEF";
+    let (int_part, frac_part) = convert_number(num, src, target)?;
+    match frac_part {
+        Some(frac) if !frac.is_empty() => Ok(format!("{}.{}", int_part, frac)),
+        _ => Ok(int_part),
+    }
+}
+
+/// Maximum number of fractional digits emitted before giving up on an exact (or repeating)
+/// fractional conversion.
+const MAX_FRACTIONAL_DIGITS: usize = 32;
+
+/// Converts `num`, optionally containing a `.` radix point, from `src` to `target`, returning
+/// the integer part and (if present) the fractional part as separate, unpadded strings.
+fn convert_number(
+    num: &str,
+    src: NumSystem,
+    target: NumSystem,
+) -> Result<(String, Option<String>), ConversionError> {
+    for system in [src, target] {
+        if let NumSystem::Radix(r) = system {
+            if (2..=36).contains(&r) {
+                continue;
+            }
+            return Err(ConversionError::UnsupportedRadix(r));
+        }
+    }
+
+    let mut parts = num.splitn(3, '.');
+    let integer_part = parts.next().unwrap_or("");
+    let fractional_part = parts.next();
+    if parts.next().is_some() {
+        return Err(ConversionError::MultipleRadixPoints);
+    }
+
+    let (is_negative, integer_part) = match integer_part.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, integer_part),
+    };
+    if is_negative && (src.is_byte_encoded() || src == NumSystem::Raw) {
+        return Err(ConversionError::InvalidBase);
+    }
+    if is_negative && (target.is_byte_encoded() || target == NumSystem::Raw) {
+        return Err(ConversionError::InvalidBase);
+    }
+
+    let decimal = decode_value(
+        if integer_part.is_empty() {
+            "0"
+        } else {
+            integer_part
+        },
+        src,
+    )?;
+    let int_result = encode_value(decimal, target)?;
+
+    let (frac_result, frac_numerator) = match fractional_part {
+        Some(frac_str) => {
+            if src.is_byte_encoded() || src == NumSystem::Raw {
+                return Err(ConversionError::InvalidBase);
+            }
+            if target.is_byte_encoded() || target == NumSystem::Raw {
+                return Err(ConversionError::InvalidBase);
+            }
+            let (numerator, denominator) = decode_fractional(frac_str, src)?;
+            (
+                Some(encode_fractional(numerator, denominator, target)?),
+                numerator,
+            )
+        }
+        None => (None, 0),
+    };
+
+    // Suppress the sign for a magnitude of exactly zero (e.g. `-0` or `-0.0`) rather than
+    // printing a meaningless "-0".
+    let int_result = if is_negative && (decimal != 0 || frac_numerator != 0) {
+        format!("-{}", int_result)
+    } else {
+        int_result
+    };
+
+    Ok((int_result, frac_result))
+}
+
+/// Parses the digits after a radix point into a numerator/denominator pair
+/// (`numerator / source_base^k`), tolerating the same separators as `decode_value`.
+fn decode_fractional(frac_str: &str, src: NumSystem) -> Result<(u128, u128), ConversionError> {
+    let cleaned: String = frac_str
+        .chars()
+        .filter(|c| *c != '_' && !c.is_ascii_whitespace())
+        .collect();
+    let source_radix = src.radix() as u128;
+
+    let mut numerator = 0u128;
+    let mut denominator = 1u128;
+    for c in cleaned.chars() {
+        let digit = digit_value(c, source_radix)?;
+        numerator = numerator
+            .checked_mul(source_radix)
+            .ok_or(ConversionError::NumberOverflow)?;
+        numerator = numerator
+            .checked_add(digit)
+            .ok_or(ConversionError::NumberOverflow)?;
+        denominator = denominator
+            .checked_mul(source_radix)
+            .ok_or(ConversionError::NumberOverflow)?;
+    }
+    Ok((numerator, denominator))
+}
+
+/// Emits `numerator / denominator` as a sequence of `target`-base digits, multiplying the
+/// remainder by `target`'s radix at each step and taking the integer part as the next digit.
+/// Stops once the remainder reaches zero or `MAX_FRACTIONAL_DIGITS` digits have been emitted.
+fn encode_fractional(
+    mut numerator: u128,
+    denominator: u128,
+    target: NumSystem,
+) -> Result<String, ConversionError> {
+    let digits = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let target_radix = target.radix() as u128;
+
+    let mut result = String::new();
+    for _ in 0..MAX_FRACTIONAL_DIGITS {
+        if numerator == 0 {
+            break;
+        }
+        numerator = numerator
+            .checked_mul(target_radix)
+            .ok_or(ConversionError::NumberOverflow)?;
+        let digit = (numerator / denominator) as usize;
+        numerator %= denominator;
+        result.push(
+            digits
+                .chars()
+                .nth(digit)
+                .ok_or(ConversionError::InvalidBase)?,
+        );
+    }
+    Ok(result)
+}
+
+/// Rust integer literal type suffixes. None of them ends with another, so at most one can
+/// match a given input.
+const INTEGER_SUFFIXES: [&str; 12] = [
+    "usize", "isize", "u128", "i128", "u64", "i64", "u32", "i32", "u16", "i16", "u8", "i8",
+];
+
+/// Strips a trailing Rust-style integer type suffix (e.g. `u8`, `i32`, `usize`) if present,
+/// leaving the string untouched if doing so would remove every character.
+fn strip_integer_suffix(s: &str) -> &str {
+    for suffix in INTEGER_SUFFIXES {
+        if let Some(stripped) = s.strip_suffix(suffix) {
+            if !stripped.is_empty() {
+                return stripped;
+            }
+        }
+    }
+    s
+}
+
+/// Parses a number string in the given `src` number system into a `u128`.
+///
+/// `NumSystem::Raw` is not a string format and is handled separately by `run`.
+fn decode_value(num: &str, src: NumSystem) -> Result<u128, ConversionError> {
+    if src == NumSystem::Raw {
+        return Err(ConversionError::InvalidBase);
+    }
+
+    if src.is_byte_encoded() {
+        let stripped = match src {
+            NumSystem::Base32 => num.strip_prefix("0t").unwrap_or(num),
+            NumSystem::Base64 => num.strip_prefix("0s").unwrap_or(num),
+            _ => unreachable!(),
+        };
+        let bytes = match src {
+            NumSystem::Base32 => base32_decode(stripped)?,
+            NumSystem::Base64 => base64_decode(stripped)?,
+            _ => unreachable!(),
+        };
+        return be_bytes_to_u128(&bytes);
+    }
 
     // Handle prefixes and determine actual number string
     let (num_str, inferred_src) = match num.to_lowercase().as_str() {
@@ -153,53 +466,217 @@ pub fn convert_base(
 
     // Use inferred source base if available, otherwise use provided src
     let source_base = inferred_src.unwrap_or(src);
+    let source_radix = source_base.radix() as u128;
+
+    // Tolerate visual digit separators (as produced by `group_digits`) and a trailing
+    // Rust-style integer type suffix (e.g. `5_000_000u64`), which is simply discarded. Suffix
+    // stripping only applies to the fixed-alphabet systems: for a large `Radix(r)`, the suffix
+    // letters (`u`, `s`, `i`, `z`, `e`) are themselves valid digits, so stripping them would
+    // silently change the value instead of just discarding decoration.
+    let cleaned: String = num_str
+        .chars()
+        .filter(|c| *c != '_' && !c.is_ascii_whitespace())
+        .collect();
+    let num_str = match source_base {
+        NumSystem::Bin | NumSystem::Oct | NumSystem::Dec | NumSystem::Hex => {
+            strip_integer_suffix(&cleaned)
+        }
+        _ => cleaned.as_str(),
+    };
 
-    // First convert to decimal
     let mut decimal = 0u128;
     for c in num_str.chars() {
-        let digit = if c.is_ascii_digit() {
-            let d = c as u128 - '0' as u128;
-            if d >= source_base as u128 {
-                return Err(ConversionError::InvalidDigit(c));
-            }
-            d
-        } else {
-            let d = (c.to_ascii_uppercase() as u128) - ('A' as u128) + 10;
-            if d >= source_base as u128 {
-                return Err(ConversionError::InvalidDigit(c));
-            }
-            d
-        };
-
+        let digit = digit_value(c, source_radix)?;
         decimal = decimal
-            .checked_mul(source_base as u128)
+            .checked_mul(source_radix)
             .ok_or(ConversionError::NumberOverflow)?;
         decimal = decimal
             .checked_add(digit)
             .ok_or(ConversionError::NumberOverflow)?;
     }
 
-    // Then convert to target base
+    Ok(decimal)
+}
+
+/// Parses a single digit character in the given radix, case-insensitively treating `A-Z`
+/// as the digits 10-35.
+fn digit_value(c: char, radix: u128) -> Result<u128, ConversionError> {
+    let d = if c.is_ascii_digit() {
+        c as u128 - '0' as u128
+    } else if c.is_ascii_alphabetic() {
+        (c.to_ascii_uppercase() as u128) - ('A' as u128) + 10
+    } else {
+        return Err(ConversionError::InvalidDigit(c));
+    };
+    if d >= radix {
+        return Err(ConversionError::InvalidDigit(c));
+    }
+    Ok(d)
+}
+
+/// Formats a `u128` value as a number string in the given `target` number system.
+///
+/// `NumSystem::Raw` is not a string format and is handled separately by `run`.
+fn encode_value(decimal: u128, target: NumSystem) -> Result<String, ConversionError> {
+    if target == NumSystem::Raw {
+        return Err(ConversionError::InvalidBase);
+    }
+
+    if target.is_byte_encoded() {
+        let bytes = u128_to_min_be_bytes(decimal);
+        return Ok(match target {
+            NumSystem::Base32 => base32_encode(&bytes),
+            NumSystem::Base64 => base64_encode(&bytes),
+            _ => unreachable!(),
+        });
+    }
+
     if decimal == 0 {
         return Ok("0".to_string());
     }
 
+    let digits = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let target_radix = target.radix() as u128;
     let mut result = Vec::new();
     let mut num = decimal;
     while num > 0 {
-        let remainder = (num % target as u128) as usize;
+        let remainder = (num % target_radix) as usize;
         result.push(
             digits
                 .chars()
                 .nth(remainder)
                 .ok_or(ConversionError::InvalidBase)?,
         );
-        num /= target as u128;
+        num /= target_radix;
     }
 
     Ok(result.iter().rev().collect())
 }
 
+/// The RFC 4648 Base32 alphabet.
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// The RFC 4648 Base64 alphabet.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Serializes a `u128` to its minimal big-endian byte representation (no leading zero bytes,
+/// except that zero itself is represented as a single zero byte).
+fn u128_to_min_be_bytes(value: u128) -> Vec<u8> {
+    if value == 0 {
+        return vec![0];
+    }
+    let all = value.to_be_bytes();
+    let first_nonzero = all.iter().position(|&b| b != 0).unwrap();
+    all[first_nonzero..].to_vec()
+}
+
+/// Interprets a big-endian byte slice as a `u128`, failing if it does not fit.
+fn be_bytes_to_u128(bytes: &[u8]) -> Result<u128, ConversionError> {
+    let first_nonzero = bytes
+        .iter()
+        .position(|&b| b != 0)
+        .unwrap_or(bytes.len().saturating_sub(1));
+    let trimmed = &bytes[first_nonzero..];
+    if trimmed.len() > 16 {
+        return Err(ConversionError::NumberOverflow);
+    }
+    let mut buf = [0u8; 16];
+    buf[16 - trimmed.len()..].copy_from_slice(trimmed);
+    Ok(u128::from_be_bytes(buf))
+}
+
+/// Encodes bytes as a standard, padded RFC 4648 Base32 string.
+fn base32_encode(bytes: &[u8]) -> String {
+    let mut output = String::new();
+    let mut bit_buffer: u32 = 0;
+    let mut bit_count = 0;
+
+    for &byte in bytes {
+        bit_buffer = (bit_buffer << 8) | byte as u32;
+        bit_count += 8;
+        while bit_count >= 5 {
+            bit_count -= 5;
+            output.push(BASE32_ALPHABET[((bit_buffer >> bit_count) & 0x1F) as usize] as char);
+        }
+    }
+    if bit_count > 0 {
+        output.push(BASE32_ALPHABET[((bit_buffer << (5 - bit_count)) & 0x1F) as usize] as char);
+    }
+    while !output.len().is_multiple_of(8) {
+        output.push('=');
+    }
+    output
+}
+
+/// Decodes a (possibly padded) RFC 4648 Base32 string into bytes.
+fn base32_decode(s: &str) -> Result<Vec<u8>, ConversionError> {
+    let mut bit_buffer: u32 = 0;
+    let mut bit_count = 0;
+    let mut bytes = Vec::new();
+
+    for c in s.trim_end_matches('=').chars() {
+        let upper = c.to_ascii_uppercase();
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|&b| b as char == upper)
+            .ok_or(ConversionError::InvalidDigit(c))? as u32;
+        bit_buffer = (bit_buffer << 5) | value;
+        bit_count += 5;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            bytes.push(((bit_buffer >> bit_count) & 0xFF) as u8);
+        }
+    }
+    Ok(bytes)
+}
+
+/// Encodes bytes as a standard, padded RFC 4648 Base64 string.
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut output = String::new();
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+        let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+
+        output.push(BASE64_ALPHABET[((triple >> 18) & 0x3F) as usize] as char);
+        output.push(BASE64_ALPHABET[((triple >> 12) & 0x3F) as usize] as char);
+        output.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[((triple >> 6) & 0x3F) as usize] as char
+        } else {
+            '='
+        });
+        output.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[(triple & 0x3F) as usize] as char
+        } else {
+            '='
+        });
+    }
+    output
+}
+
+/// Decodes a (possibly padded) RFC 4648 Base64 string into bytes.
+fn base64_decode(s: &str) -> Result<Vec<u8>, ConversionError> {
+    let mut bit_buffer: u32 = 0;
+    let mut bit_count = 0;
+    let mut bytes = Vec::new();
+
+    for c in s.trim_end_matches('=').chars() {
+        let value = BASE64_ALPHABET
+            .iter()
+            .position(|&b| b as char == c)
+            .ok_or(ConversionError::InvalidDigit(c))? as u32;
+        bit_buffer = (bit_buffer << 6) | value;
+        bit_count += 6;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            bytes.push(((bit_buffer >> bit_count) & 0xFF) as u8);
+        }
+    }
+    Ok(bytes)
+}
+
 /// Groups digits in a number string with specified spacing.
 ///
 /// # Arguments
@@ -242,10 +719,12 @@ pub fn pad_width(num: &str, width: u32) -> String {
 /// Executes the number conversion process based on the provided configuration.
 ///
 /// This function performs the following steps:
-/// 1. Converts the number from source base to target base.
-/// 2. Pads the result to the specified width.
-/// 3. Groups digits according to the grouping configuration.
-/// 4. Prints the final result to stdout.
+/// 1. Gathers the input numbers: when `src_base` is `NumSystem::Raw`, a single raw big-endian
+///    byte stream read from stdin (`config.number` is then ignored); otherwise from
+///    `config.number` if non-empty, or else as whitespace-separated tokens read from stdin.
+/// 2. Converts each number from source base to target base.
+/// 3. Writes each result, either as a padded and grouped ASCII string (one per line) or, when
+///    `tgt_base` is `NumSystem::Raw`, as packed big-endian bytes, all on stdout.
 ///
 /// # Arguments
 ///
@@ -262,17 +741,143 @@ pub fn pad_width(num: &str, width: u32) -> String {
 /// use nconv::{Config, NumSystem};
 ///
 /// let config = Config {
-///     number: String::from("1010"),
+///     number: vec![String::from("1010")],
 ///     src_base: NumSystem::Bin,
 ///     tgt_base: NumSystem::Dec,
 ///     width: 0,
 ///     grouping: 0,
+///     twos_complement: false,
+///     bits: 8,
 /// };
 ///
 /// nconv::run(&config).unwrap();  // Prints: 10
 /// ```
 pub fn run(config: &Config) -> Result<(), ConversionError> {
-    let result = convert_base(&config.number, config.src_base, config.tgt_base)?;
+    if config.src_base == NumSystem::Raw {
+        let mut bytes = Vec::new();
+        std::io::stdin()
+            .read_to_end(&mut bytes)
+            .map_err(ConversionError::Io)?;
+        return write_decimal_result(be_bytes_to_u128(&bytes)?, config);
+    }
+
+    if !config.number.is_empty() {
+        for number in &config.number {
+            write_number_result(number, config)?;
+        }
+        return Ok(());
+    }
+
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .map_err(ConversionError::Io)?;
+    for token in input.split_whitespace() {
+        write_number_result(token, config)?;
+    }
+
+    Ok(())
+}
+
+/// Converts a single number string and writes the result to stdout, either as packed
+/// big-endian bytes (`tgt_base == NumSystem::Raw`) or as a padded, grouped ASCII string
+/// followed by a newline. Padding and grouping apply only to the integer part's digits (not
+/// its `-` sign, if any); any fractional part (after a `.` radix point) is appended unpadded.
+///
+/// `config.twos_complement` routes the number through `write_signed_number_result` instead,
+/// which emits negative values as a two's-complement bit pattern rather than a `-`-prefixed
+/// magnitude.
+fn write_number_result(num: &str, config: &Config) -> Result<(), ConversionError> {
+    if config.twos_complement {
+        return write_signed_number_result(num, config);
+    }
+
+    if config.tgt_base == NumSystem::Raw {
+        return write_decimal_result(decode_value(num, config.src_base)?, config);
+    }
+
+    let (int_part, frac_part) = convert_number(num, config.src_base, config.tgt_base)?;
+    let (sign, digits) = match int_part.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", int_part.as_str()),
+    };
+    let digits = pad_width(digits, config.width);
+    let digits = group_digits(&digits, config.grouping);
+    match frac_part {
+        Some(frac) if !frac.is_empty() => println!("{}{}.{}", sign, digits, frac),
+        _ => println!("{}{}", sign, digits),
+    }
+
+    Ok(())
+}
+
+/// Writes a number to stdout as its two's-complement bit pattern in `config.bits` bits. A
+/// leading `-` is parsed off and the remaining digits are decoded as the magnitude.
+///
+/// Fractional parts and the `Raw` number system are not supported for two's-complement output.
+fn write_signed_number_result(num: &str, config: &Config) -> Result<(), ConversionError> {
+    if config.src_base == NumSystem::Raw || config.tgt_base == NumSystem::Raw || num.contains('.') {
+        return Err(ConversionError::InvalidBase);
+    }
+
+    let (is_negative, magnitude_str) = match num.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, num),
+    };
+    let magnitude = decode_value(magnitude_str, config.src_base)?;
+
+    let value = twos_complement_value(is_negative, magnitude, config.bits)?;
+    let result = encode_value(value, config.tgt_base)?;
+    let result = pad_width(&result, config.width);
+    let result = group_digits(&result, config.grouping);
+    println!("{}", result);
+
+    Ok(())
+}
+
+/// Computes the `bits`-wide two's-complement representation of a signed magnitude: `magnitude`
+/// unchanged if non-negative, or `2^bits - magnitude` if negative. Errors if `bits` is too large
+/// for the `2^bits` modulus to fit in a `u128`, or if `magnitude` falls outside the signed range
+/// `[-2^(bits-1), 2^(bits-1) - 1]` representable in `bits` bits.
+fn twos_complement_value(
+    is_negative: bool,
+    magnitude: u128,
+    bits: u32,
+) -> Result<u128, ConversionError> {
+    if magnitude == 0 {
+        return Ok(0);
+    }
+
+    let modulus = 1u128
+        .checked_shl(bits)
+        .ok_or(ConversionError::UnsupportedBitWidth(bits))?;
+    let half_modulus = modulus / 2;
+
+    if is_negative {
+        if magnitude > half_modulus {
+            return Err(ConversionError::MagnitudeExceedsBitWidth(bits));
+        }
+        Ok(modulus - magnitude)
+    } else {
+        if magnitude >= half_modulus {
+            return Err(ConversionError::MagnitudeExceedsBitWidth(bits));
+        }
+        Ok(magnitude)
+    }
+}
+
+/// Writes an already-decoded decimal value to stdout, either as packed big-endian bytes
+/// (`tgt_base == NumSystem::Raw`) or as a padded, grouped ASCII string followed by a newline.
+/// Used for the decimal-only (non-fractional) paths: raw-source input and raw-target output.
+fn write_decimal_result(decimal: u128, config: &Config) -> Result<(), ConversionError> {
+    if config.tgt_base == NumSystem::Raw {
+        let bytes = u128_to_min_be_bytes(decimal);
+        return std::io::stdout()
+            .write_all(&bytes)
+            .map_err(ConversionError::Io);
+    }
+
+    let result = encode_value(decimal, config.tgt_base)?;
     let result = pad_width(&result, config.width);
     let result = group_digits(&result, config.grouping);
     println!("{}", result);
@@ -325,6 +930,110 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn convert_base_strips_digit_separators_and_type_suffixes() -> Result<(), ConversionError> {
+        assert_eq!(
+            convert_base("5_000_000", NumSystem::Dec, NumSystem::Dec)?,
+            "5000000"
+        );
+        assert_eq!(
+            convert_base("1 234", NumSystem::Dec, NumSystem::Dec)?,
+            "1234"
+        );
+        assert_eq!(
+            convert_base("5_000_000u64", NumSystem::Dec, NumSystem::Dec)?,
+            "5000000"
+        );
+        assert_eq!(
+            convert_base("0x7Fi32", NumSystem::Hex, NumSystem::Dec)?,
+            "127"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn convert_base_does_not_strip_suffix_like_digits_in_large_radixes() -> Result<(), ConversionError> {
+        // In base 36, 'u' and '8' are both valid digits, so "1u8" must decode as the three
+        // digits 1, 30, 8 rather than have "u8" stripped as an integer-type suffix; lowercase
+        // and uppercase must agree since digit_value is case-insensitive.
+        assert_eq!(
+            convert_base("1u8", NumSystem::Radix(36), NumSystem::Dec)?,
+            "2384"
+        );
+        assert_eq!(
+            convert_base("1U8", NumSystem::Radix(36), NumSystem::Dec)?,
+            "2384"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn convert_base_handles_fractional_conversion() -> Result<(), ConversionError> {
+        assert_eq!(
+            convert_base("3.14", NumSystem::Dec, NumSystem::Hex)?,
+            "3.23D70A3D70A3D70A3D70A3D70A3D70A3"
+        );
+        assert_eq!(
+            convert_base("0b101.101", NumSystem::Bin, NumSystem::Dec)?,
+            "5.625"
+        );
+        assert_eq!(convert_base("1.5", NumSystem::Dec, NumSystem::Bin)?, "1.1");
+        assert_eq!(convert_base("10.", NumSystem::Dec, NumSystem::Hex)?, "A");
+        assert_eq!(convert_base(".5", NumSystem::Dec, NumSystem::Bin)?, "0.1");
+        Ok(())
+    }
+
+    #[test]
+    fn convert_base_rejects_multiple_radix_points() {
+        assert!(matches!(
+            convert_base("1.2.3", NumSystem::Dec, NumSystem::Hex),
+            Err(ConversionError::MultipleRadixPoints)
+        ));
+    }
+
+    #[test]
+    fn twos_complement_value_encodes_negative_and_positive_values() -> Result<(), ConversionError> {
+        assert_eq!(twos_complement_value(true, 1, 8)?, 255);
+        assert_eq!(twos_complement_value(true, 1, 16)?, 65535);
+        assert_eq!(twos_complement_value(true, 128, 8)?, 128);
+        assert_eq!(twos_complement_value(false, 127, 8)?, 127);
+        assert_eq!(twos_complement_value(false, 0, 8)?, 0);
+        assert_eq!(twos_complement_value(true, 0, 8)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn twos_complement_value_rejects_out_of_range_magnitudes() {
+        assert!(matches!(
+            twos_complement_value(true, 129, 8),
+            Err(ConversionError::MagnitudeExceedsBitWidth(8))
+        ));
+        assert!(matches!(
+            twos_complement_value(false, 128, 8),
+            Err(ConversionError::MagnitudeExceedsBitWidth(8))
+        ));
+    }
+
+    #[test]
+    fn twos_complement_value_rejects_unsupported_bit_widths() {
+        assert!(matches!(
+            twos_complement_value(true, 1, 128),
+            Err(ConversionError::UnsupportedBitWidth(128))
+        ));
+    }
+
+    #[test]
+    fn convert_base_rejects_fractional_parts_for_byte_encoded_systems() {
+        assert!(matches!(
+            convert_base("1.5", NumSystem::Dec, NumSystem::Base64),
+            Err(ConversionError::InvalidBase)
+        ));
+        assert!(matches!(
+            convert_base("1.5", NumSystem::Dec, NumSystem::Raw),
+            Err(ConversionError::InvalidBase)
+        ));
+    }
+
     #[test]
     fn convert_base_returns_invalid_digit_error_on_invalid_digits() {
         assert!(matches!(
@@ -339,6 +1048,42 @@ mod tests {
             convert_base("0o89", NumSystem::Oct, NumSystem::Dec),
             Err(ConversionError::InvalidDigit(_))
         ));
+        assert!(matches!(
+            convert_base("1-2", NumSystem::Dec, NumSystem::Hex),
+            Err(ConversionError::InvalidDigit('-'))
+        ));
+        assert!(matches!(
+            convert_base("1:2", NumSystem::Dec, NumSystem::Hex),
+            Err(ConversionError::InvalidDigit(':'))
+        ));
+        assert!(matches!(
+            convert_base("1!", NumSystem::Dec, NumSystem::Hex),
+            Err(ConversionError::InvalidDigit('!'))
+        ));
+    }
+
+    #[test]
+    fn convert_base_handles_signed_decimal_input() -> Result<(), ConversionError> {
+        assert_eq!(convert_base("-1", NumSystem::Dec, NumSystem::Hex)?, "-1");
+        assert_eq!(convert_base("-255", NumSystem::Dec, NumSystem::Hex)?, "-FF");
+        assert_eq!(
+            convert_base("-1.5", NumSystem::Dec, NumSystem::Bin)?,
+            "-1.1"
+        );
+        assert_eq!(convert_base("-0", NumSystem::Dec, NumSystem::Hex)?, "0");
+        Ok(())
+    }
+
+    #[test]
+    fn convert_base_rejects_signed_input_for_byte_encoded_and_raw_systems() {
+        assert!(matches!(
+            convert_base("-1", NumSystem::Dec, NumSystem::Base64),
+            Err(ConversionError::InvalidBase)
+        ));
+        assert!(matches!(
+            convert_base("-1", NumSystem::Dec, NumSystem::Raw),
+            Err(ConversionError::InvalidBase)
+        ));
     }
 
     #[test]
@@ -356,6 +1101,107 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn convert_base_supports_arbitrary_radixes() -> Result<(), ConversionError> {
+        assert_eq!(
+            convert_base("0011", NumSystem::Radix(2), NumSystem::Radix(36))?,
+            "3"
+        );
+        assert_eq!(
+            convert_base("Z", NumSystem::Radix(36), NumSystem::Dec)?,
+            "35"
+        );
+        assert_eq!(
+            convert_base("255", NumSystem::Dec, NumSystem::Radix(3))?,
+            "100110"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn convert_base_rejects_out_of_range_radixes() {
+        assert!(matches!(
+            convert_base("10", NumSystem::Radix(1), NumSystem::Dec),
+            Err(ConversionError::UnsupportedRadix(1))
+        ));
+        assert!(matches!(
+            convert_base("10", NumSystem::Dec, NumSystem::Radix(37)),
+            Err(ConversionError::UnsupportedRadix(37))
+        ));
+    }
+
+    #[test]
+    fn convert_base_round_trips_base32_and_base64() -> Result<(), ConversionError> {
+        assert_eq!(
+            convert_base("0xAABB", NumSystem::Hex, NumSystem::Base64)?,
+            "qrs="
+        );
+        assert_eq!(
+            convert_base("qrs=", NumSystem::Base64, NumSystem::Hex)?,
+            "AABB"
+        );
+        assert_eq!(
+            convert_base("0xAABB", NumSystem::Hex, NumSystem::Base32)?,
+            "VK5Q===="
+        );
+        assert_eq!(
+            convert_base("VK5Q====", NumSystem::Base32, NumSystem::Hex)?,
+            "AABB"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn convert_base_strips_base32_and_base64_prefixes() -> Result<(), ConversionError> {
+        assert_eq!(
+            convert_base("0sqrs=", NumSystem::Base64, NumSystem::Hex)?,
+            "AABB"
+        );
+        assert_eq!(
+            convert_base("0tVK5Q====", NumSystem::Base32, NumSystem::Hex)?,
+            "AABB"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn num_system_from_str_parses_named_and_numeric_radixes() {
+        assert_eq!("bin".parse::<NumSystem>().unwrap(), NumSystem::Bin);
+        assert_eq!("HEX".parse::<NumSystem>().unwrap(), NumSystem::Hex);
+        assert_eq!("20".parse::<NumSystem>().unwrap(), NumSystem::Radix(20));
+        assert!("1".parse::<NumSystem>().is_err());
+        assert!("notabase".parse::<NumSystem>().is_err());
+    }
+
+    #[test]
+    fn num_system_from_str_parses_base32_base64_and_raw() {
+        assert_eq!("base32".parse::<NumSystem>().unwrap(), NumSystem::Base32);
+        assert_eq!("B32".parse::<NumSystem>().unwrap(), NumSystem::Base32);
+        assert_eq!("base64".parse::<NumSystem>().unwrap(), NumSystem::Base64);
+        assert_eq!("B64".parse::<NumSystem>().unwrap(), NumSystem::Base64);
+        assert_eq!("raw".parse::<NumSystem>().unwrap(), NumSystem::Raw);
+        assert_eq!("RAW".parse::<NumSystem>().unwrap(), NumSystem::Raw);
+    }
+
+    #[test]
+    fn raw_byte_round_trip_preserves_value() {
+        let bytes = u128_to_min_be_bytes(0xAABB);
+        assert_eq!(bytes, vec![0xAA, 0xBB]);
+        assert_eq!(be_bytes_to_u128(&bytes).unwrap(), 0xAABB);
+
+        assert_eq!(u128_to_min_be_bytes(0), vec![0]);
+        assert_eq!(be_bytes_to_u128(&[0]).unwrap(), 0);
+    }
+
+    #[test]
+    fn raw_rejects_byte_slices_too_large_for_u128() {
+        let too_big = vec![0xFFu8; 17];
+        assert!(matches!(
+            be_bytes_to_u128(&too_big),
+            Err(ConversionError::NumberOverflow)
+        ));
+    }
+
     #[test]
     fn convert_base_successfull_converts_0_to_all_bases() -> Result<(), ConversionError> {
         assert_eq!(convert_base("0x0", NumSystem::Hex, NumSystem::Dec)?, "0");
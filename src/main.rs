@@ -1,22 +1,22 @@
 use clap::Parser;
 
 #[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
+#[command(version, about, long_about = None, allow_negative_numbers = true)]
 struct Args {
     #[arg(
-        value_enum,
-        help = "source number system"
+        help = "source number system: bin, oct, dec, hex, base32, base64, raw, or a radix from 2 to 36"
     )]
     src_base: nconv::NumSystem,
 
     #[arg(
-        value_enum,
-        help = "target number system"
+        help = "target number system: bin, oct, dec, hex, base32, base64, raw, or a radix from 2 to 36"
     )]
     tgt_base: nconv::NumSystem,
 
-    #[arg(help = "a positive integer in the source number system")]
-    number: String,
+    #[arg(
+        help = "one or more (optionally negative) integers in the source number system; if omitted, whitespace-separated numbers are read from stdin (or, when source is \"raw\", raw bytes are read from stdin)"
+    )]
+    number: Vec<String>,
 
     #[arg(
         short = 'g',
@@ -30,11 +30,26 @@ struct Args {
     #[arg(
         short = 'w',
         long,
-        default_value_t = 1, 
+        default_value_t = 1,
         value_parser = clap::value_parser!(u32).range(1..=256),
         help = "minimum number of digits in the output"
     )]
     width: u32,
+
+    #[arg(
+        long,
+        help = "emit negative target values as their two's-complement bit pattern (in --bits bits) instead of a '-'-prefixed magnitude"
+    )]
+    twos_complement: bool,
+
+    #[arg(
+        short = 'b',
+        long,
+        default_value_t = 8,
+        value_parser = clap::value_parser!(u32).range(1..=127),
+        help = "bit width used to compute the two's-complement modulus for --twos-complement"
+    )]
+    bits: u32,
 }
 
 fn main() {
@@ -45,6 +60,8 @@ fn main() {
         args.number,
         args.grouping,
         args.width,
+        args.twos_complement,
+        args.bits,
     );
 
     if let Err(e) = nconv::run(&config) {